@@ -1,8 +1,10 @@
 use std::{
-    borrow::Cow,
+    borrow::{Borrow, Cow},
     cmp::Ordering,
+    ffi::{CStr, CString, OsStr, OsString},
     fmt::{self, Debug},
     hash::{Hash, Hasher},
+    path::{Path, PathBuf},
     sync::Arc,
 };
 
@@ -11,20 +13,176 @@ pub enum ArcCow<'a, T: ?Sized> {
     Owned(Arc<T>),
 }
 
-impl<T: ?Sized + PartialEq> PartialEq for ArcCow<'_, T> {
-    fn eq(&self, other: &Self) -> bool {
+/// Constructs an `Arc<T>` from the `Owned` type of a `ToOwned` impl.
+///
+/// `ToOwned::Owned -> Arc<T>` has no blanket impl on stable, so this trait
+/// is implemented for the concrete leaf types `str` and `[T]` only; it has
+/// no impl for `Path`, `OsStr` or `CStr`.
+#[doc(hidden)]
+pub trait ArcFromOwned<T: ?Sized> {
+    fn arc_from_owned(owned: T::Owned) -> Arc<T>
+    where
+        T: ToOwned;
+}
+
+impl ArcFromOwned<str> for str {
+    fn arc_from_owned(owned: String) -> Arc<str> {
+        Arc::from(owned)
+    }
+}
+
+impl<T: Clone> ArcFromOwned<[T]> for [T] {
+    fn arc_from_owned(owned: <[T] as ToOwned>::Owned) -> Arc<[T]> {
+        Arc::from(owned)
+    }
+}
+
+impl<'a, T: ?Sized> ArcCow<'a, T> {
+    /// Returns `true` if `self` holds a borrowed reference.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Returns `true` if `self` holds an owned `Arc`.
+    pub fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+}
+
+impl<'a, T: ?Sized + ToOwned> ArcCow<'a, T>
+where
+    T::Owned: Borrow<T>,
+{
+    /// Consumes `self`, returning the owned value.
+    ///
+    /// Both variants go through `ToOwned`: a `Borrowed` reference is cloned
+    /// directly, and an `Owned` `Arc` has its pointee cloned.
+    pub fn into_owned(self) -> T::Owned {
+        match self {
+            Self::Borrowed(borrowed) => borrowed.to_owned(),
+            Self::Owned(owned) => owned.as_ref().to_owned(),
+        }
+    }
+}
+
+impl<'a, T: ?Sized + ToOwned> ArcCow<'a, T>
+where
+    T: ArcFromOwned<T>,
+{
+    /// Returns a mutable reference to the owned contents, cloning on write.
+    ///
+    /// After this call `self` is always `Owned` with a strong count of 1:
+    /// a `Borrowed` variant is cloned into a fresh `Arc`, and an `Owned`
+    /// variant is cloned only if it is not already uniquely held.
+    pub fn to_mut(&mut self) -> &mut T {
+        match *self {
+            Self::Borrowed(borrowed) => {
+                *self = Self::Owned(T::arc_from_owned(borrowed.to_owned()));
+                match self {
+                    Self::Owned(owned) => Arc::get_mut(owned).expect("just created, uniquely held"),
+                    Self::Borrowed(_) => unreachable!(),
+                }
+            }
+            Self::Owned(ref mut owned) => {
+                if Arc::get_mut(owned).is_none() {
+                    *owned = T::arc_from_owned(owned.as_ref().to_owned());
+                }
+                Arc::get_mut(owned).expect("just made unique")
+            }
+        }
+    }
+
+    /// Consumes `self`, promoting a `Borrowed` variant into a fresh `Arc`.
+    ///
+    /// A `Borrowed(r)` is cloned via `r.to_owned()` into a new `Arc<T>`;
+    /// an `Owned(arc)` is returned unchanged.
+    pub fn into_arc(self) -> Arc<T> {
+        match self {
+            Self::Borrowed(borrowed) => T::arc_from_owned(borrowed.to_owned()),
+            Self::Owned(owned) => owned,
+        }
+    }
+}
+
+impl<T: Clone> ArcCow<'_, T> {
+    /// Reclaims the owned value if the `Arc` is uniquely held.
+    ///
+    /// Returns `Ok(T)` when `self` is `Owned` with a strong count of 1,
+    /// otherwise hands back `self` unchanged in `Err`.
+    pub fn try_unwrap(self) -> Result<T, Self> {
+        match self {
+            Self::Owned(owned) => Arc::try_unwrap(owned).map_err(Self::Owned),
+            borrowed => Err(borrowed),
+        }
+    }
+}
+
+impl ArcCow<'_, Path> {
+    /// Converts the path to a `Cow<str>`, replacing invalid UTF-8 sequences.
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        self.as_ref().to_string_lossy()
+    }
+}
+
+impl ArcCow<'_, OsStr> {
+    /// Converts the string to a `Cow<str>`, replacing invalid UTF-8 sequences.
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        self.as_ref().to_string_lossy()
+    }
+}
+
+impl<'a, 'b, T: ?Sized + PartialEq> PartialEq<ArcCow<'b, T>> for ArcCow<'a, T> {
+    fn eq(&self, other: &ArcCow<'b, T>) -> bool {
         let a = self.as_ref();
         let b = other.as_ref();
         a == b
     }
 }
 
-impl<T: ?Sized + PartialOrd> PartialOrd for ArcCow<'_, T> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl<'a, 'b, T: ?Sized + PartialOrd> PartialOrd<ArcCow<'b, T>> for ArcCow<'a, T> {
+    fn partial_cmp(&self, other: &ArcCow<'b, T>) -> Option<Ordering> {
         self.as_ref().partial_cmp(other.as_ref())
     }
 }
 
+/// Implements a symmetric pair of `PartialEq`/`PartialOrd` impls between
+/// `ArcCow<'_, $base>` and `$rhs`, comparing through `as_ref()` on both sides.
+macro_rules! impl_cross_cmp {
+    ($base:ty, $rhs:ty $(, $generic:ident)?) => {
+        impl<'a, $($generic: PartialEq)?> PartialEq<$rhs> for ArcCow<'a, $base> {
+            fn eq(&self, other: &$rhs) -> bool {
+                self.as_ref() == AsRef::<$base>::as_ref(other)
+            }
+        }
+
+        impl<'a, $($generic: PartialEq)?> PartialEq<ArcCow<'a, $base>> for $rhs {
+            fn eq(&self, other: &ArcCow<'a, $base>) -> bool {
+                AsRef::<$base>::as_ref(self) == other.as_ref()
+            }
+        }
+
+        impl<'a, $($generic: PartialOrd)?> PartialOrd<$rhs> for ArcCow<'a, $base> {
+            fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                self.as_ref().partial_cmp(AsRef::<$base>::as_ref(other))
+            }
+        }
+
+        impl<'a, $($generic: PartialOrd)?> PartialOrd<ArcCow<'a, $base>> for $rhs {
+            fn partial_cmp(&self, other: &ArcCow<'a, $base>) -> Option<Ordering> {
+                AsRef::<$base>::as_ref(self).partial_cmp(other.as_ref())
+            }
+        }
+    };
+}
+
+impl_cross_cmp!(str, str);
+impl_cross_cmp!(str, &str);
+impl_cross_cmp!(str, String);
+impl_cross_cmp!(str, Cow<'_, str>);
+impl_cross_cmp!([T], [T], T);
+impl_cross_cmp!([T], &[T], T);
+impl_cross_cmp!([T], Vec<T>, T);
+
 impl<T: ?Sized + Ord> Ord for ArcCow<'_, T> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.as_ref().cmp(other.as_ref())
@@ -102,6 +260,24 @@ impl<'a> From<&'a str> for ArcCow<'a, [u8]> {
     }
 }
 
+impl From<PathBuf> for ArcCow<'_, Path> {
+    fn from(value: PathBuf) -> Self {
+        Self::Owned(Arc::from(value))
+    }
+}
+
+impl From<OsString> for ArcCow<'_, OsStr> {
+    fn from(value: OsString) -> Self {
+        Self::Owned(Arc::from(value))
+    }
+}
+
+impl From<CString> for ArcCow<'_, CStr> {
+    fn from(value: CString) -> Self {
+        Self::Owned(Arc::from(value))
+    }
+}
+
 impl<T: ?Sized + ToOwned> std::borrow::Borrow<T> for ArcCow<'_, T> {
     fn borrow(&self) -> &T {
         match self {
@@ -140,6 +316,98 @@ impl<T: ?Sized + Debug> Debug for ArcCow<'_, T> {
     }
 }
 
+impl<T: ?Sized + fmt::Display> fmt::Display for ArcCow<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<'a> std::ops::Add<&str> for ArcCow<'a, str> {
+    type Output = ArcCow<'a, str>;
+
+    fn add(mut self, rhs: &str) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl std::ops::AddAssign<&str> for ArcCow<'_, str> {
+    fn add_assign(&mut self, rhs: &str) {
+        let mut s = String::with_capacity(self.len() + rhs.len());
+        s.push_str(self.as_ref());
+        s.push_str(rhs);
+        *self = Self::Owned(Arc::from(s));
+    }
+}
+
+impl<'a> std::ops::Add<String> for ArcCow<'a, str> {
+    type Output = ArcCow<'a, str>;
+
+    fn add(self, rhs: String) -> Self::Output {
+        self + rhs.as_str()
+    }
+}
+
+impl std::ops::AddAssign<String> for ArcCow<'_, str> {
+    fn add_assign(&mut self, rhs: String) {
+        *self += rhs.as_str();
+    }
+}
+
+impl<'a> std::ops::Add<ArcCow<'_, str>> for ArcCow<'a, str> {
+    type Output = ArcCow<'a, str>;
+
+    fn add(self, rhs: ArcCow<'_, str>) -> Self::Output {
+        self + rhs.as_ref()
+    }
+}
+
+impl std::ops::AddAssign<ArcCow<'_, str>> for ArcCow<'_, str> {
+    fn add_assign(&mut self, rhs: ArcCow<'_, str>) {
+        *self += rhs.as_ref();
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ?Sized + serde::Serialize> serde::Serialize for ArcCow<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ArcCow<'_, str> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Self::Owned(Arc::from(s)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Clone> serde::Deserialize<'de> for ArcCow<'_, [T]> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(|v| Self::Owned(Arc::from(v)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Clone> serde::Deserialize<'de> for ArcCow<'_, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(|v| Self::Owned(Arc::new(v)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,9 +672,9 @@ mod tests2 {
 
         // Clone Owned
         let owned_arc: Arc<str> = Arc::from("owned");
-        let arc_cow_owned = ArcCow::Owned(owned_arc);
-        // assert_eq!(Arc::strong_count(arc_cow_owned.clone().into_arc()), 2);
-        // assert_eq!(Arc::strong_count(&arc_cow_owned.into_arc()), 1);
+        let arc_cow_owned: ArcCow<str> = ArcCow::Owned(owned_arc);
+        assert_eq!(Arc::strong_count(&arc_cow_owned.clone().into_arc()), 2);
+        assert_eq!(Arc::strong_count(&arc_cow_owned.into_arc()), 1);
     }
 
     #[test]
@@ -457,20 +725,206 @@ mod tests2 {
         assert_eq!(format!("{:?}", owned), "\"debug\"");
     }
 
-    // Helper extension trait for tests
-    trait ArcCowExt<T: ?Sized> {
-        fn into_arc(self) -> Arc<T>;
+    #[test]
+    fn test_cross_type_eq_str() {
+        let arc_cow: ArcCow<str> = ArcCow::from("abc");
+
+        assert_eq!(arc_cow, "abc");
+        assert_eq!(arc_cow, *"abc");
+        assert_eq!(arc_cow, "abc".to_string());
+        assert_eq!(arc_cow, Cow::Borrowed("abc"));
+
+        // Symmetric: literal on the left.
+        assert_eq!(*"abc", arc_cow);
+        assert_eq!("abc", arc_cow);
+        assert_eq!("abc".to_string(), arc_cow);
+        assert_eq!(Cow::Borrowed("abc"), arc_cow);
+
+        // Two ArcCows with different lifetimes.
+        let owned = "abc".to_string();
+        let other: ArcCow<str> = ArcCow::from(&owned);
+        assert_eq!(arc_cow, other);
+    }
+
+    #[test]
+    fn test_cross_type_ord_str() {
+        let arc_cow: ArcCow<str> = ArcCow::from("b");
+        assert!(arc_cow > "a");
+        assert!(arc_cow < "c");
     }
 
-    impl<T: ?Sized + Clone> ArcCowExt<T> for ArcCow<'_, T>
-    where
-        T: ToOwned<Owned = T>,
-    {
-        fn into_arc(self) -> Arc<T> {
-            match self {
-                ArcCow::Borrowed(b) => Arc::new(b.to_owned()),
-                ArcCow::Owned(o) => o,
-            }
+    #[test]
+    fn test_cross_type_eq_slice() {
+        let arc_cow: ArcCow<[i32]> = ArcCow::from(vec![1, 2, 3]);
+
+        assert_eq!(arc_cow, *vec![1, 2, 3].into_boxed_slice());
+        assert_eq!(arc_cow, &[1, 2, 3][..]);
+        assert_eq!(arc_cow, vec![1, 2, 3]);
+        assert_eq!(vec![1, 2, 3], arc_cow);
+    }
+
+    #[test]
+    fn test_is_borrowed_is_owned() {
+        let borrowed: ArcCow<str> = ArcCow::from("abc");
+        let owned: ArcCow<str> = ArcCow::from("abc".to_string());
+
+        assert!(borrowed.is_borrowed());
+        assert!(!borrowed.is_owned());
+        assert!(owned.is_owned());
+        assert!(!owned.is_borrowed());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let borrowed: ArcCow<str> = ArcCow::from("abc");
+        let owned: ArcCow<str> = ArcCow::from("abc".to_string());
+
+        let json = serde_json::to_string(&borrowed).unwrap();
+        assert_eq!(json, "\"abc\"");
+        assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+
+        let deserialized: ArcCow<str> = serde_json::from_str(&json).unwrap();
+        assert!(deserialized.is_owned());
+        assert_eq!(deserialized.as_ref(), "abc");
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let borrowed: ArcCow<str> = ArcCow::from("abc");
+        assert_eq!(borrowed.into_owned(), "abc".to_string());
+
+        let owned: ArcCow<str> = ArcCow::from("xyz".to_string());
+        assert_eq!(owned.into_owned(), "xyz".to_string());
+    }
+
+    #[test]
+    fn test_to_mut_borrowed_promotes_to_owned() {
+        let mut arc_cow: ArcCow<str> = ArcCow::from("hello");
+        assert!(arc_cow.is_borrowed());
+
+        arc_cow.to_mut().make_ascii_uppercase();
+
+        assert!(arc_cow.is_owned());
+        assert_eq!(arc_cow.as_ref(), "HELLO");
+    }
+
+    #[test]
+    fn test_to_mut_owned_clones_when_shared() {
+        let arc: Arc<str> = Arc::from("shared");
+        let mut arc_cow: ArcCow<str> = ArcCow::Owned(arc.clone());
+
+        arc_cow.to_mut().make_ascii_uppercase();
+
+        assert_eq!(arc_cow.as_ref(), "SHARED");
+        // The original Arc was untouched since it was not uniquely held.
+        assert_eq!(arc.as_ref(), "shared");
+        if let ArcCow::Owned(owned) = &arc_cow {
+            assert_eq!(Arc::strong_count(owned), 1);
+        }
+    }
+
+    #[test]
+    fn test_to_mut_slice() {
+        let mut arc_cow: ArcCow<[i32]> = ArcCow::from(vec![1, 2, 3]);
+        arc_cow.to_mut()[0] = 42;
+        assert_eq!(&*arc_cow, &[42, 2, 3]);
+    }
+
+    #[test]
+    fn test_into_arc() {
+        let borrowed: ArcCow<str> = ArcCow::from("abc");
+        let arc = borrowed.into_arc();
+        assert_eq!(&*arc, "abc");
+
+        let owned_arc: Arc<str> = Arc::from("xyz");
+        let owned: ArcCow<str> = ArcCow::Owned(owned_arc.clone());
+        let arc = owned.into_arc();
+        assert!(Arc::ptr_eq(&arc, &owned_arc));
+    }
+
+    #[test]
+    fn test_try_unwrap() {
+        let owned: ArcCow<i32> = ArcCow::Owned(Arc::new(42));
+        assert_eq!(owned.try_unwrap(), Ok(42));
+
+        let arc = Arc::new(7);
+        let shared: ArcCow<i32> = ArcCow::Owned(arc.clone());
+        match shared.try_unwrap() {
+            Ok(_) => panic!("shared Arc should not unwrap"),
+            Err(returned) => assert_eq!(*returned, 7),
+        }
+
+        let value = 9;
+        let borrowed: ArcCow<i32> = ArcCow::from(&value);
+        match borrowed.try_unwrap() {
+            Ok(_) => panic!("borrowed variant should not unwrap"),
+            Err(returned) => assert_eq!(*returned, 9),
         }
     }
+
+    #[test]
+    fn test_path_conversions() {
+        let path = Path::new("/tmp/file.txt");
+        let borrowed: ArcCow<Path> = ArcCow::from(path);
+        assert!(borrowed.is_borrowed());
+        assert_eq!(borrowed.as_ref(), path);
+
+        let owned: ArcCow<Path> = ArcCow::from(PathBuf::from("/tmp/file.txt"));
+        assert!(owned.is_owned());
+        assert_eq!(owned.as_ref(), path);
+
+        assert_eq!(borrowed.to_string_lossy(), "/tmp/file.txt");
+    }
+
+    #[test]
+    fn test_os_str_conversions() {
+        let os_str = OsStr::new("hello");
+        let borrowed: ArcCow<OsStr> = ArcCow::from(os_str);
+        assert!(borrowed.is_borrowed());
+
+        let owned: ArcCow<OsStr> = ArcCow::from(OsString::from("hello"));
+        assert!(owned.is_owned());
+        assert_eq!(owned.as_ref(), os_str);
+
+        assert_eq!(borrowed.to_string_lossy(), "hello");
+    }
+
+    #[test]
+    fn test_c_str_conversions() {
+        let c_string = CString::new("hello").unwrap();
+        let c_str: &CStr = c_string.as_c_str();
+
+        let borrowed: ArcCow<CStr> = ArcCow::from(c_str);
+        assert!(borrowed.is_borrowed());
+
+        let owned: ArcCow<CStr> = ArcCow::from(c_string.clone());
+        assert!(owned.is_owned());
+        assert_eq!(owned.as_ref(), c_str);
+    }
+
+    #[test]
+    fn test_display() {
+        let borrowed: ArcCow<str> = ArcCow::from("hi");
+        let owned: ArcCow<str> = ArcCow::from("there".to_string());
+        assert_eq!(format!("{}", borrowed), "hi");
+        assert_eq!(format!("{}", owned), "there");
+    }
+
+    #[test]
+    fn test_add_and_add_assign() {
+        let a: ArcCow<str> = ArcCow::from("foo");
+        let b = a + "bar";
+        assert!(b.is_owned());
+        assert_eq!(b.as_ref(), "foobar");
+
+        let mut c: ArcCow<str> = ArcCow::from("foo");
+        c += "bar".to_string();
+        assert_eq!(c.as_ref(), "foobar");
+
+        let d: ArcCow<str> = ArcCow::from("foo".to_string());
+        let e: ArcCow<str> = ArcCow::from("bar");
+        let f = d + e;
+        assert_eq!(f.as_ref(), "foobar");
+    }
 }
\ No newline at end of file